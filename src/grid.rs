@@ -1,11 +1,65 @@
+use std::collections::{HashSet, VecDeque};
 use std::ops::{Index, IndexMut};
 
 #[derive(Debug, Clone)]
 pub struct UnequalColumnsError;
 
+/// Which neighbors [`Grid::flood_fill`]/[`Grid::connected_regions`] expand into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Connectivity {
+    /// Only the row and column neighbors (up, down, left, right).
+    Orthogonal,
+    /// Row, column, and diagonal neighbors.
+    All,
+}
+
+/// A direction to step in from a `(row, col)` coordinate, for use with [`Grid::step`] and
+/// [`Grid::ray`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+    UpLeft,
+    UpRight,
+    DownLeft,
+    DownRight,
+}
+
+impl Direction {
+    fn delta(self) -> (isize, isize) {
+        match self {
+            Direction::Up => (-1, 0),
+            Direction::Down => (1, 0),
+            Direction::Left => (0, -1),
+            Direction::Right => (0, 1),
+            Direction::UpLeft => (-1, -1),
+            Direction::UpRight => (-1, 1),
+            Direction::DownLeft => (1, -1),
+            Direction::DownRight => (1, 1),
+        }
+    }
+}
+
+/// Controls how a [`Grid`]'s flat backing store maps a `(row, col)` coordinate to a linear
+/// offset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Order {
+    /// `offset = row * cols + col`. Rows are stored contiguously.
+    #[default]
+    RowMajor,
+    /// `offset = col * rows + row`. Columns are stored contiguously.
+    ColumnMajor,
+}
+
 #[derive(Debug)]
 /// A 2D Grid of values. Uses a cooridinate system where x increases going down
 /// and y increases to the right.
+///
+/// Backed by a single flat `Vec<T>` rather than a `Vec<Vec<T>>`, giving contiguous memory and a
+/// single allocation regardless of `rows`/`cols`.
 /// # Example
 /// ```
 /// use griddy::Grid;
@@ -21,7 +75,10 @@ pub struct UnequalColumnsError;
 /// assert_eq!(grid[1][1], 8);
 ///```
 pub struct Grid<T> {
-    grid: Vec<Vec<T>>,
+    data: Vec<T>,
+    rows: usize,
+    cols: usize,
+    order: Order,
 }
 
 impl<T> Grid<T> {
@@ -31,12 +88,47 @@ impl<T> Grid<T> {
     where
         T: Clone,
     {
-        let mut grid = Vec::with_capacity(rows);
-        for _ in 0..rows {
-            grid.push(vec![value.clone(); cols]);
+        Grid {
+            data: vec![value; rows * cols],
+            rows,
+            cols,
+            order: Order::RowMajor,
         }
+    }
 
-        Grid { grid }
+    /// Initialize a 2D grid like [`Grid::init`], but with an explicit backing [`Order`].
+    pub fn init_with_order(rows: usize, cols: usize, value: T, order: Order) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            data: vec![value; rows * cols],
+            rows,
+            cols,
+            order,
+        }
+    }
+
+    /// Initialize a 2D grid by filling each cell with `f(row, col)`, for contents that depend on
+    /// position (a checkerboard, a distance-from-center field) without the `T: Clone` bound that
+    /// [`Grid::init`] imposes.
+    pub fn with_generator<F>(rows: usize, cols: usize, mut f: F) -> Grid<T>
+    where
+        F: FnMut(usize, usize) -> T,
+    {
+        let mut data = Vec::with_capacity(rows * cols);
+        for row in 0..rows {
+            for col in 0..cols {
+                data.push(f(row, col));
+            }
+        }
+
+        Grid {
+            data,
+            rows,
+            cols,
+            order: Order::RowMajor,
+        }
     }
 
     /// Initialize a Grid from a 2D vector. Returned `UnequalColumnsError` if all the rows are not
@@ -48,65 +140,179 @@ impl<T> Grid<T> {
         if c.len() > 1 {
             return Err(UnequalColumnsError);
         }
-        Ok(Grid { grid })
+        Ok(Grid::from_2d_unchecked(grid))
     }
 
     /// Initialize a Grid from a 2D vector.
     pub fn from_2d_unchecked(grid: Vec<Vec<T>>) -> Grid<T> {
-        Grid { grid }
+        let rows = grid.len();
+        let cols = grid.first().map_or(0, |r| r.len());
+        let data = grid.into_iter().flatten().collect();
+        Grid {
+            data,
+            rows,
+            cols,
+            order: Order::RowMajor,
+        }
     }
 
-    /// Insert a row at idx.
+    /// Initialize a Grid by splitting `input` on newlines and mapping each byte of each line
+    /// through `f`, one row per line. Returns `UnequalColumnsError` if the lines are not all the
+    /// same byte length.
+    /// # Example
+    /// ```
+    /// use griddy::Grid;
+    ///
+    /// let grid = Grid::from_bytes(".#.\n#.#", |b| b == b'#').unwrap();
+    /// assert_eq!(grid[0], vec![false, true, false]);
+    /// assert_eq!(grid[1], vec![true, false, true]);
+    /// ```
+    pub fn from_bytes<F>(input: &str, mut f: F) -> Result<Grid<T>, UnequalColumnsError>
+    where
+        F: FnMut(u8) -> T,
+    {
+        let grid: Vec<Vec<T>> = input
+            .lines()
+            .map(|line| line.bytes().map(&mut f).collect())
+            .collect();
+        Grid::from_2d(grid)
+    }
+
+    /// Like [`Grid::from_bytes`], but does not validate that every line has the same length.
+    pub fn from_bytes_unchecked<F>(input: &str, mut f: F) -> Grid<T>
+    where
+        F: FnMut(u8) -> T,
+    {
+        let grid: Vec<Vec<T>> = input
+            .lines()
+            .map(|line| line.bytes().map(&mut f).collect())
+            .collect();
+        Grid::from_2d_unchecked(grid)
+    }
+
+    /// Like [`Grid::from_bytes`], but iterates `char`s instead of bytes, for non-ASCII input.
+    pub fn from_chars<F>(input: &str, mut f: F) -> Result<Grid<T>, UnequalColumnsError>
+    where
+        F: FnMut(char) -> T,
+    {
+        let grid: Vec<Vec<T>> = input
+            .lines()
+            .map(|line| line.chars().map(&mut f).collect())
+            .collect();
+        Grid::from_2d(grid)
+    }
+
+    /// The [`Order`] this grid's backing store is laid out in.
+    pub fn order(&self) -> Order {
+        self.order
+    }
+
+    /// Translate a `(row, col)` coordinate into an offset into the flat backing store,
+    /// according to `self.order`.
+    fn linear_index(&self, row: usize, col: usize) -> usize {
+        match self.order {
+            Order::RowMajor => row * self.cols + col,
+            Order::ColumnMajor => col * self.rows + row,
+        }
+    }
+
+    /// Row-oriented operations (`Index<usize>`, `rows`, `rows_mut`, `insert_row`,
+    /// `truncate_rows`) require contiguous rows in the backing store, which only holds for
+    /// `Order::RowMajor`.
+    fn assert_row_major(&self, method: &str) {
+        if self.order != Order::RowMajor {
+            panic!(
+                "`{}` requires Order::RowMajor, grid is Order::ColumnMajor",
+                method
+            );
+        }
+    }
+
+    /// Returns a reference to the cell at `(row, col)`, or `None` if out of bounds. Unlike
+    /// `grid[row][col]`, this never panics.
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        if row >= self.rows || col >= self.cols {
+            return None;
+        }
+
+        Some(&self.data[self.linear_index(row, col)])
+    }
+
+    /// Returns a mutable reference to the cell at `(row, col)`, or `None` if out of bounds.
+    /// Unlike `grid[row][col]`, this never panics.
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        if row >= self.rows || col >= self.cols {
+            return None;
+        }
+
+        let idx = self.linear_index(row, col);
+        Some(&mut self.data[idx])
+    }
+
+    /// Insert a row at idx. Panics if `row.len() != cols_len()`. Panics if `order()` is
+    /// `ColumnMajor`.
     pub fn insert_row(&mut self, idx: usize, row: Vec<T>) {
-        self.grid.insert(idx, row);
+        self.assert_row_major("insert_row");
+        if row.len() != self.cols {
+            panic!(
+                "row has {:?} elements, grid has {:?} cols.",
+                row.len(),
+                self.cols
+            );
+        }
+
+        let at = idx * self.cols;
+        self.data.splice(at..at, row);
+        self.rows += 1;
     }
 
-    /// Transpose
+    /// Transpose the grid. Since a column-major grid is the transpose of the equivalent
+    /// row-major layout (and vice versa), this just swaps `rows`/`cols` and flips `order`,
+    /// cloning the backing data as-is rather than copying element by element.
     pub fn transpose(&self) -> Grid<T>
     where
-        T: Copy,
+        T: Clone,
     {
-        let mut g = Grid::init(
-            self.cols_len(),
-            self.rows_len(),
-            self.grid[0][0]
-        );
-        for x in 0..self.cols_len() {
-            for y in 0..self.rows_len() {
-                g[x][y] = self.grid[y][x];
-            }
+        Grid {
+            data: self.data.clone(),
+            rows: self.cols,
+            cols: self.rows,
+            order: match self.order {
+                Order::RowMajor => Order::ColumnMajor,
+                Order::ColumnMajor => Order::RowMajor,
+            },
         }
-        g
     }
 
+    /// Print the grid, one row per line. Panics if `order()` is `ColumnMajor`.
     pub fn print(&self)
     where
-        T: std::fmt::Debug
+        T: std::fmt::Debug,
     {
         for r in self.rows() {
             println!("{:?}", r);
         }
     }
 
+    /// Rotate the grid 90 degrees clockwise. The result is always `Order::RowMajor`, regardless
+    /// of `self.order()`.
     pub fn rotate(&mut self)
     where
-        T: Copy
+        T: Clone,
     {
-        let mut temp = vec![];
-
-        for column in 0..self.rows_len() {
-            let mut t = vec![];
-            for row in (0..self.rows_len()).rev() {
-                t.push(self.grid[row][column]);
+        let new_rows = self.cols;
+        let new_cols = self.rows;
+        let mut data = Vec::with_capacity(self.data.len());
+        for i in 0..new_rows {
+            for j in 0..new_cols {
+                data.push(self.data[self.linear_index(self.rows - 1 - j, i)].clone());
             }
-            temp.push(t);
         }
 
-        for i in 0..self.rows_len() {
-            for j in 0..self.rows_len() {
-                self.grid[i][j] = temp[i][j];
-            }
-        }
+        self.data = data;
+        self.rows = new_rows;
+        self.cols = new_cols;
+        self.order = Order::RowMajor;
     }
 
     pub fn flip_y(&mut self) {
@@ -117,15 +323,12 @@ impl<T> Grid<T> {
 
     /// The number of rows.
     pub fn rows_len(&self) -> usize {
-        self.grid.len()
+        self.rows
     }
 
     /// The number of columns.
     pub fn cols_len(&self) -> usize {
-        match self.grid.len() {
-            0 => 0,
-            _ => self.grid[0].len(),
-        }
+        self.cols
     }
 
     /// Returns all the coordinates to the left (x decreases) of the coordinate `(row, col)`.
@@ -254,6 +457,36 @@ impl<T> Grid<T> {
         n
     }
 
+    /// Applies `dir` as a signed step from `(row, col)`, returning `None` if the result leaves
+    /// the grid.
+    pub fn step(&self, row: usize, col: usize, dir: Direction) -> Option<(usize, usize)> {
+        if row >= self.rows_len() || col >= self.cols_len() {
+            return None;
+        }
+
+        let (dr, dc) = dir.delta();
+        let r = row as isize + dr;
+        let c = col as isize + dc;
+        if r < 0 || c < 0 || r as usize >= self.rows_len() || c as usize >= self.cols_len() {
+            return None;
+        }
+
+        Some((r as usize, c as usize))
+    }
+
+    /// Repeatedly steps from `start` in `dir`, collecting every coordinate reached until the
+    /// edge of the grid. Does not include `start` itself.
+    pub fn ray(&self, start: (usize, usize), dir: Direction) -> Vec<(usize, usize)> {
+        let mut coords = Vec::new();
+        let mut current = start;
+        while let Some(next) = self.step(current.0, current.1, dir) {
+            coords.push(next);
+            current = next;
+        }
+
+        coords
+    }
+
     /// Returns all valid coordinates surrounding the coordinate `(row, col)`.
     pub fn neighbors(&self, row: usize, col: usize) -> Vec<(usize, usize)> {
         let mut n = self.row_neighbors(row, col);
@@ -262,19 +495,103 @@ impl<T> Grid<T> {
         n
     }
 
-    /// Truncate the number of rows to `size`
+    /// Breadth-first flood-fill starting at `start`, expanding into a neighbor whenever
+    /// `predicate(current_value, neighbor_value)` returns `true`. `connectivity` controls whether
+    /// diagonal neighbors are considered. Returns every coordinate reached, including `start`.
+    pub fn flood_fill<P>(
+        &self,
+        start: (usize, usize),
+        connectivity: Connectivity,
+        mut predicate: P,
+    ) -> Vec<(usize, usize)>
+    where
+        P: FnMut(&T, &T) -> bool,
+    {
+        if start.0 >= self.rows_len() || start.1 >= self.cols_len() {
+            return vec![];
+        }
+
+        let mut visited = HashSet::new();
+        let mut reached = Vec::new();
+        let mut frontier = VecDeque::new();
+
+        visited.insert(start);
+        reached.push(start);
+        frontier.push_back(start);
+
+        while let Some((row, col)) = frontier.pop_front() {
+            let candidates = match connectivity {
+                Connectivity::Orthogonal => {
+                    let mut n = self.row_neighbors(row, col);
+                    n.append(&mut self.col_neighbors(row, col));
+                    n
+                }
+                Connectivity::All => self.neighbors(row, col),
+            };
+
+            for (nr, nc) in candidates {
+                if visited.contains(&(nr, nc)) {
+                    continue;
+                }
+
+                if predicate(&self[(row, col)], &self[(nr, nc)]) {
+                    visited.insert((nr, nc));
+                    reached.push((nr, nc));
+                    frontier.push_back((nr, nc));
+                }
+            }
+        }
+
+        reached
+    }
+
+    /// Partition the whole grid into connected regions by repeatedly flood-filling from the
+    /// first unvisited cell, using [`Grid::flood_fill`].
+    pub fn connected_regions<P>(
+        &self,
+        connectivity: Connectivity,
+        mut predicate: P,
+    ) -> Vec<Vec<(usize, usize)>>
+    where
+        P: FnMut(&T, &T) -> bool,
+    {
+        let mut seen = HashSet::new();
+        let mut regions = Vec::new();
+
+        for row in 0..self.rows_len() {
+            for col in 0..self.cols_len() {
+                if seen.contains(&(row, col)) {
+                    continue;
+                }
+
+                let mut region = self.flood_fill((row, col), connectivity, &mut predicate);
+                region.retain(|coord| !seen.contains(coord));
+                seen.extend(region.iter().copied());
+                regions.push(region);
+            }
+        }
+
+        regions
+    }
+
+    /// Truncate the number of rows to `size`. Like `Vec::truncate`, has no effect if `size` is
+    /// greater than or equal to `rows_len()`. Panics if `order()` is `ColumnMajor`.
     pub fn truncate_rows(&mut self, size: usize) {
-        self.grid.truncate(size);
+        self.assert_row_major("truncate_rows");
+        self.data.truncate(size * self.cols);
+        self.rows = self.rows.min(size);
     }
 
-    /// Return an iter over the rows
-    pub fn rows(&self) -> std::slice::Iter<'_, Vec<T>> {
-        self.grid.iter()
+    /// Return an iter over the rows. Panics if `order()` is `ColumnMajor`.
+    pub fn rows(&self) -> std::slice::Chunks<'_, T> {
+        self.assert_row_major("rows");
+        self.data.chunks(self.cols)
     }
 
-    /// Return a mut iter over the rows
-    pub fn rows_mut(&mut self) -> std::slice::IterMut<'_, Vec<T>> {
-        self.grid.iter_mut()
+    /// Return a mut iter over the rows. Panics if `order()` is `ColumnMajor`.
+    pub fn rows_mut(&mut self) -> std::slice::ChunksMut<'_, T> {
+        self.assert_row_major("rows_mut");
+        self.data.chunks_mut(self.cols)
     }
 
     /// Fold the 2d grid "up" at `row`. Takes a closure that passes in a reference to the `new`
@@ -290,15 +607,17 @@ impl<T> Grid<T> {
     /// ```text
     /// 1, 2, 3
     /// ```
+    /// Panics if `order()` is `ColumnMajor`.
     pub fn fold_at_row<F>(&mut self, row: usize, mut f: F) -> usize
     where
         F: FnMut(&T, &T) -> T,
     {
+        self.assert_row_major("fold_at_row");
         let mut new_y = (0..row).rev();
         for y in (row + 1)..self.rows_len() {
             if let Some(new_y_coord) = new_y.next() {
                 for x in 0..self.cols_len() {
-                    self.grid[new_y_coord][x] = f(&self.grid[new_y_coord][x], &self.grid[y][x]);
+                    self[(new_y_coord, x)] = f(&self[(new_y_coord, x)], &self[(y, x)]);
                 }
             } else {
                 break;
@@ -309,31 +628,127 @@ impl<T> Grid<T> {
     }
 }
 
+/// Indexes into a row. Panics if `order()` is `ColumnMajor`.
 impl<T> Index<usize> for Grid<T> {
-    type Output = Vec<T>;
+    type Output = [T];
 
     fn index(&self, idx: usize) -> &Self::Output {
-        if idx >= self.grid.len() {
+        self.assert_row_major("index");
+        if idx >= self.rows {
             panic!(
                 "index {:?} out of bounds. Grid has {:?} rows.",
-                self.grid.len(), idx
+                idx, self.rows
             );
         }
 
-        &self.grid[idx]
+        &self.data[idx * self.cols..(idx + 1) * self.cols]
     }
 }
 
+/// Mutably indexes into a row. Panics if `order()` is `ColumnMajor`.
 impl<T> IndexMut<usize> for Grid<T> {
     fn index_mut(&mut self, idx: usize) -> &mut Self::Output {
-        if idx >= self.grid.len() {
+        self.assert_row_major("index_mut");
+        if idx >= self.rows {
             panic!(
                 "index {:?} out of bounds. Grid has {:?} rows.",
-                self.grid.len(), idx
+                idx, self.rows
+            );
+        }
+
+        let cols = self.cols;
+        &mut self.data[idx * cols..(idx + 1) * cols]
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        if row >= self.rows || col >= self.cols {
+            panic!(
+                "coordinate {:?} out of bounds. Grid is {:?}x{:?}.",
+                (row, col),
+                self.rows,
+                self.cols
+            );
+        }
+
+        &self.data[self.linear_index(row, col)]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        if row >= self.rows || col >= self.cols {
+            panic!(
+                "coordinate {:?} out of bounds. Grid is {:?}x{:?}.",
+                (row, col),
+                self.rows,
+                self.cols
             );
         }
 
-        &mut self.grid[idx]
+        let idx = self.linear_index(row, col);
+        &mut self.data[idx]
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for Grid<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        #[derive(serde::Serialize)]
+        struct GridRepr<'a, T> {
+            rows: usize,
+            cols: usize,
+            order: Order,
+            data: &'a [T],
+        }
+
+        GridRepr {
+            rows: self.rows,
+            cols: self.cols,
+            order: self.order,
+            data: &self.data,
+        }
+        .serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for Grid<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(serde::Deserialize)]
+        struct GridRepr<T> {
+            rows: usize,
+            cols: usize,
+            order: Order,
+            data: Vec<T>,
+        }
+
+        let repr = GridRepr::deserialize(deserializer)?;
+        if repr.rows * repr.cols != repr.data.len() {
+            return Err(serde::de::Error::custom(format!(
+                "declared {} rows * {} cols = {}, but found {} elements",
+                repr.rows,
+                repr.cols,
+                repr.rows * repr.cols,
+                repr.data.len()
+            )));
+        }
+
+        Ok(Grid {
+            data: repr.data,
+            rows: repr.rows,
+            cols: repr.cols,
+            order: repr.order,
+        })
     }
 }
 
@@ -362,6 +777,51 @@ mod tests {
         assert_eq!(grid[0][0], 1);
     }
 
+    #[test]
+    fn tuple_index() {
+        let mut grid = Grid::init(10, 8, 0);
+        grid[(0, 0)] = 1;
+        grid[(1, 2)] = 5;
+        assert_eq!(grid[(0, 0)], 1);
+        assert_eq!(grid[(1, 2)], 5);
+        assert_eq!(grid[1][2], 5);
+    }
+
+    #[test]
+    fn column_major_order() {
+        let mut grid = Grid::init_with_order(2, 3, 0, Order::ColumnMajor);
+        assert_eq!(grid.order(), Order::ColumnMajor);
+        assert_eq!(grid.rows_len(), 2);
+        assert_eq!(grid.cols_len(), 3);
+
+        for row in 0..2 {
+            for col in 0..3 {
+                grid[(row, col)] = row * 3 + col;
+            }
+        }
+
+        for row in 0..2 {
+            for col in 0..3 {
+                assert_eq!(grid[(row, col)], row * 3 + col);
+                assert_eq!(grid.get(row, col), Some(&(row * 3 + col)));
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "`index` requires Order::RowMajor")]
+    fn column_major_index_panics() {
+        let grid = Grid::init_with_order(2, 3, 0, Order::ColumnMajor);
+        let _ = grid[0];
+    }
+
+    #[test]
+    #[should_panic(expected = "`rows` requires Order::RowMajor")]
+    fn column_major_rows_panics() {
+        let grid = Grid::init_with_order(2, 3, 0, Order::ColumnMajor);
+        let _ = grid.rows();
+    }
+
     #[test]
     fn row_neighbors() {
         let grid = Grid::init(5, 5, 0);
@@ -450,8 +910,9 @@ mod tests {
         let num_rows = 7;
         let num_cols = 10;
         let mut grid = Grid::init(num_rows, num_cols, 0);
-        grid[6] = vec![1; num_cols];
-        
+        for x in 0..num_cols {
+            grid[(6, x)] = 1;
+        }
 
         let fold_row = (num_rows / 2) - 1;
         let rl = grid.fold_at_row(fold_row, |new, old| new + old);
@@ -465,8 +926,9 @@ mod tests {
         let num_rows = 7;
         let num_cols = 10;
         let mut grid = Grid::init(num_rows, num_cols, 0);
-        grid[6] = vec![1; num_cols];
-        
+        for x in 0..num_cols {
+            grid[(6, x)] = 1;
+        }
 
         let fold_row = num_rows / 2;
         grid.fold_at_row(fold_row, |new, old| new + old);
@@ -479,8 +941,9 @@ mod tests {
         let num_rows = 7;
         let num_cols = 10;
         let mut grid = Grid::init(num_rows, num_cols, 0);
-        grid[6] = vec![1; num_cols];
-        
+        for x in 0..num_cols {
+            grid[(6, x)] = 1;
+        }
 
         let fold_row = (num_rows / 2) + 1;
         grid.fold_at_row(fold_row, |new, old| new + old);
@@ -497,6 +960,22 @@ mod tests {
         assert_eq!(rl, 6);
     }
 
+    #[test]
+    fn truncate_rows_past_current_len_is_a_no_op() {
+        let mut grid = Grid::init(3, 2, 0);
+        grid.truncate_rows(5);
+        assert_eq!(grid.rows_len(), 3);
+        assert_eq!(grid.get(2, 1), Some(&0));
+        assert_eq!(grid.get(4, 0), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn insert_row_wrong_length_panics() {
+        let mut grid = Grid::init(2, 2, 0);
+        grid.insert_row(0, vec![1, 2, 3, 4]);
+    }
+
     #[test]
     fn row_left_coords() {
         let grid = Grid::init(5, 5, 0);
@@ -595,4 +1074,231 @@ mod tests {
             v,
         );
     }
+
+    #[test]
+    fn with_generator() {
+        let grid = Grid::with_generator(3, 3, |row, col| row * 3 + col);
+        assert_eq!(grid.rows_len(), 3);
+        assert_eq!(grid.cols_len(), 3);
+        assert_eq!(grid[0], vec![0, 1, 2]);
+        assert_eq!(grid[1], vec![3, 4, 5]);
+        assert_eq!(grid[2], vec![6, 7, 8]);
+    }
+
+    #[test]
+    fn from_bytes() {
+        let grid = Grid::from_bytes(".#.\n#.#", |b| b == b'#').unwrap();
+        assert_eq!(grid.rows_len(), 2);
+        assert_eq!(grid.cols_len(), 3);
+        assert_eq!(grid[0], vec![false, true, false]);
+        assert_eq!(grid[1], vec![true, false, true]);
+    }
+
+    #[test]
+    fn from_bytes_unequal_columns() {
+        let err = Grid::from_bytes(".#.\n#.", |b| b == b'#');
+        assert!(err.is_err());
+    }
+
+    #[test]
+    fn from_bytes_unchecked() {
+        let grid = Grid::from_bytes_unchecked(".#.\n#.#", |b| b == b'#');
+        assert_eq!(grid.rows_len(), 2);
+        assert_eq!(grid[0], vec![false, true, false]);
+        assert_eq!(grid[1], vec![true, false, true]);
+    }
+
+    #[test]
+    fn from_chars() {
+        let grid = Grid::from_chars("αβ\nγδ", |c| c).unwrap();
+        assert_eq!(grid[0], vec!['α', 'β']);
+        assert_eq!(grid[1], vec!['γ', 'δ']);
+    }
+
+    #[test]
+    fn step() {
+        let grid = Grid::init(3, 3, 0);
+        assert_eq!(grid.step(1, 1, Direction::Up), Some((0, 1)));
+        assert_eq!(grid.step(1, 1, Direction::DownRight), Some((2, 2)));
+        assert_eq!(grid.step(0, 0, Direction::Up), None);
+        assert_eq!(grid.step(0, 0, Direction::UpLeft), None);
+        assert_eq!(grid.step(2, 2, Direction::DownRight), None);
+    }
+
+    #[test]
+    fn ray() {
+        let grid = Grid::init(5, 5, 0);
+        assert_eq!(
+            grid.ray((2, 2), Direction::Right),
+            vec![(2, 3), (2, 4)],
+        );
+        assert_eq!(
+            grid.ray((2, 2), Direction::UpLeft),
+            vec![(1, 1), (0, 0)],
+        );
+        assert_eq!(grid.ray((0, 0), Direction::Up), Vec::new());
+    }
+
+    #[test]
+    fn get() {
+        let grid = Grid::from_2d_unchecked(vec![vec![1, 2], vec![3, 4]]);
+        assert_eq!(grid.get(0, 1), Some(&2));
+        assert_eq!(grid.get(1, 1), Some(&4));
+        assert_eq!(grid.get(2, 0), None);
+        assert_eq!(grid.get(0, 2), None);
+    }
+
+    #[test]
+    fn get_mut() {
+        let mut grid = Grid::from_2d_unchecked(vec![vec![1, 2], vec![3, 4]]);
+        if let Some(cell) = grid.get_mut(0, 1) {
+            *cell = 9;
+        }
+        assert_eq!(grid[(0, 1)], 9);
+        assert_eq!(grid.get_mut(5, 5), None);
+    }
+
+    #[test]
+    fn flood_fill_orthogonal() {
+        let grid = Grid::from_2d_unchecked(vec![
+            vec![1, 1, 0],
+            vec![1, 0, 0],
+            vec![0, 0, 1],
+        ]);
+
+        let mut region = grid.flood_fill((0, 0), Connectivity::Orthogonal, |a, b| a == b);
+        region.sort();
+        assert_eq!(region, vec![(0, 0), (0, 1), (1, 0)]);
+    }
+
+    #[test]
+    fn flood_fill_all_includes_diagonals() {
+        let grid = Grid::from_2d_unchecked(vec![
+            vec![1, 0, 1],
+            vec![0, 1, 0],
+            vec![1, 0, 1],
+        ]);
+
+        let mut region = grid.flood_fill((0, 0), Connectivity::All, |a, b| a == b);
+        region.sort();
+        assert_eq!(region, vec![(0, 0), (0, 2), (1, 1), (2, 0), (2, 2)]);
+    }
+
+    #[test]
+    fn flood_fill_out_of_bounds() {
+        let grid = Grid::init(3, 3, 0);
+        assert_eq!(grid.flood_fill((5, 5), Connectivity::All, |a, b| a == b), vec![]);
+    }
+
+    #[test]
+    fn connected_regions() {
+        let grid = Grid::from_2d_unchecked(vec![
+            vec![1, 1, 0],
+            vec![1, 0, 0],
+            vec![0, 0, 1],
+        ]);
+
+        let mut regions: Vec<Vec<(usize, usize)>> = grid
+            .connected_regions(Connectivity::Orthogonal, |a, b| a == b)
+            .into_iter()
+            .map(|mut r| {
+                r.sort();
+                r
+            })
+            .collect();
+        regions.sort();
+
+        assert_eq!(
+            regions,
+            vec![
+                vec![(0, 0), (0, 1), (1, 0)],
+                vec![(0, 2), (1, 1), (1, 2), (2, 0), (2, 1)],
+                vec![(2, 2)],
+            ]
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_roundtrip() {
+        let grid = Grid::from_2d_unchecked(vec![vec![1, 2], vec![3, 4]]);
+        let json = serde_json::to_string(&grid).unwrap();
+        let back: Grid<i32> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.rows_len(), 2);
+        assert_eq!(back.cols_len(), 2);
+        assert_eq!(back[0], vec![1, 2]);
+        assert_eq!(back[1], vec![3, 4]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_rejects_mismatched_dimensions() {
+        let json = r#"{"rows":2,"cols":2,"order":"RowMajor","data":[1,2,3]}"#;
+        let result: Result<Grid<i32>, _> = serde_json::from_str(json);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn transpose() {
+        let grid = Grid::from_2d_unchecked(vec![
+            vec![1, 2, 3],
+            vec![4, 5, 6],
+        ]);
+
+        let t = grid.transpose();
+        assert_eq!(t.rows_len(), 3);
+        assert_eq!(t.cols_len(), 2);
+        assert_eq!(t.order(), Order::ColumnMajor);
+
+        let expected = [[1, 4], [2, 5], [3, 6]];
+        for (row, cols) in expected.into_iter().enumerate() {
+            for (col, value) in cols.into_iter().enumerate() {
+                assert_eq!(t[(row, col)], value);
+            }
+        }
+    }
+
+    #[test]
+    fn transpose_twice_round_trips_order() {
+        let grid = Grid::init_with_order(2, 3, 0, Order::ColumnMajor);
+        let t = grid.transpose();
+        assert_eq!(t.order(), Order::RowMajor);
+
+        let back = t.transpose();
+        assert_eq!(back.order(), Order::ColumnMajor);
+        assert_eq!(back.rows_len(), grid.rows_len());
+        assert_eq!(back.cols_len(), grid.cols_len());
+    }
+
+    #[test]
+    fn rotate_square() {
+        let mut grid = Grid::from_2d_unchecked(vec![
+            vec![1, 2, 3],
+            vec![4, 5, 6],
+            vec![7, 8, 9],
+        ]);
+
+        grid.rotate();
+
+        assert_eq!(grid.order(), Order::RowMajor);
+        assert_eq!(grid.rows_len(), 3);
+        assert_eq!(grid.cols_len(), 3);
+        assert_eq!(grid[0], vec![7, 4, 1]);
+        assert_eq!(grid[1], vec![8, 5, 2]);
+        assert_eq!(grid[2], vec![9, 6, 3]);
+    }
+
+    #[test]
+    fn rotate_rectangular() {
+        let mut grid = Grid::from_2d_unchecked(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+
+        grid.rotate();
+
+        assert_eq!(grid.order(), Order::RowMajor);
+        assert_eq!(grid.rows_len(), 3);
+        assert_eq!(grid.cols_len(), 2);
+        assert_eq!(grid[0], vec![4, 1]);
+        assert_eq!(grid[1], vec![5, 2]);
+        assert_eq!(grid[2], vec![6, 3]);
+    }
 }